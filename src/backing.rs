@@ -0,0 +1,173 @@
+use crate::{LoafHeader, LoafPart, USIZE_BYTES};
+use core::ops::Range;
+use core::sync::atomic;
+use std::alloc;
+
+/// A type-erased set of operations for a `LoafPart`'s backing allocation.
+///
+/// Every `LoafPart` carries a `data` pointer plus a `&'static Backing`, so the
+/// rest of the crate can `clone`/`drop`/`to_owned` a loaf without knowing
+/// whether it's backed by the crate's own header allocation, an adopted
+/// `Vec<u8>`/`Box<[u8]>`, or a `&'static` slice.
+pub(crate) struct Backing {
+    pub(crate) clone: unsafe fn(data: *mut (), range: Range<usize>) -> LoafPart,
+    pub(crate) drop: unsafe fn(data: *mut ()),
+    pub(crate) to_owned: unsafe fn(data: *mut (), range: Range<usize>) -> Vec<u8>,
+    /// Whether `data` is the sole remaining reference to its backing.
+    pub(crate) is_unique: unsafe fn(data: *mut ()) -> bool,
+    /// The full address range `data`'s backing ever covered. Only called
+    /// once `is_unique` has confirmed exclusive ownership.
+    pub(crate) full_range: unsafe fn(data: *mut ()) -> Range<usize>,
+}
+
+unsafe fn to_owned_from_range(range: Range<usize>) -> Vec<u8> {
+    // safe! caller guarantees `range` is the live, valid address range of a loaf.
+    std::slice::from_raw_parts(range.start as *const u8, range.len()).to_vec()
+}
+
+// --- the crate's own (LoafHeader, bytes) allocation; the original backing ---
+
+pub(crate) static HEADER_BACKING: Backing = Backing {
+    clone: header_clone,
+    drop: header_drop,
+    to_owned: header_to_owned,
+    is_unique: header_is_unique,
+    full_range: header_full_range,
+};
+
+unsafe fn header_clone(data: *mut (), range: Range<usize>) -> LoafPart {
+    // safe! pointer points to initialized LoafHeader value,
+    // which is currently not mut accessed elsewhere
+    let header: &LoafHeader = unsafe { &*(data as *const LoafHeader) };
+    let was = header.arc.fetch_add(1, atomic::Ordering::SeqCst);
+    if was > isize::MAX as usize {
+        std::process::abort();
+    }
+    unsafe { LoafPart::from_raw_parts(data, &HEADER_BACKING, range) }
+}
+
+unsafe fn header_drop(data: *mut ()) {
+    let header: &LoafHeader = unsafe { &*(data as *const LoafHeader) };
+    let was = header.arc.fetch_sub(1, atomic::Ordering::SeqCst);
+    if was == 1 {
+        // I am the final owner! drop!
+        unsafe {
+            let layout = alloc::Layout::from_size_align_unchecked(header.alloc_size, USIZE_BYTES);
+            alloc::dealloc(data as *mut u8, layout)
+        }
+    }
+}
+
+unsafe fn header_to_owned(_data: *mut (), range: Range<usize>) -> Vec<u8> {
+    unsafe { to_owned_from_range(range) }
+}
+
+unsafe fn header_is_unique(data: *mut ()) -> bool {
+    let header: &LoafHeader = unsafe { &*(data as *const LoafHeader) };
+    header.arc.load(atomic::Ordering::Acquire) == 1
+}
+
+unsafe fn header_full_range(data: *mut ()) -> Range<usize> {
+    let header: &LoafHeader = unsafe { &*(data as *const LoafHeader) };
+    let start = data as usize + core::mem::size_of::<LoafHeader>();
+    start..(start + header.data_len)
+}
+
+// --- an adopted `Vec<u8>`/`Box<[u8]>` allocation, shared via its own refcount ---
+
+struct BoxBacking {
+    arc: atomic::AtomicUsize,
+    boxed: Box<[u8]>,
+}
+
+pub(crate) static BOX_BACKING: Backing = Backing {
+    clone: box_clone,
+    drop: box_drop,
+    to_owned: box_to_owned,
+    is_unique: box_is_unique,
+    full_range: box_full_range,
+};
+
+pub(crate) fn adopt_boxed(boxed: Box<[u8]>) -> LoafPart {
+    let start = boxed.as_ptr() as usize;
+    let end = start + boxed.len();
+    let data = Box::into_raw(Box::new(BoxBacking {
+        arc: 1.into(),
+        boxed,
+    })) as *mut ();
+    unsafe { LoafPart::from_raw_parts(data, &BOX_BACKING, start..end) }
+}
+
+unsafe fn box_clone(data: *mut (), range: Range<usize>) -> LoafPart {
+    let backing: &BoxBacking = unsafe { &*(data as *const BoxBacking) };
+    let was = backing.arc.fetch_add(1, atomic::Ordering::SeqCst);
+    if was > isize::MAX as usize {
+        std::process::abort();
+    }
+    unsafe { LoafPart::from_raw_parts(data, &BOX_BACKING, range) }
+}
+
+unsafe fn box_drop(data: *mut ()) {
+    let backing: &BoxBacking = unsafe { &*(data as *const BoxBacking) };
+    let was = backing.arc.fetch_sub(1, atomic::Ordering::SeqCst);
+    if was == 1 {
+        // I am the final owner! drop the Box<[u8]> along with its control block.
+        unsafe { drop(Box::from_raw(data as *mut BoxBacking)) };
+    }
+}
+
+unsafe fn box_to_owned(_data: *mut (), range: Range<usize>) -> Vec<u8> {
+    unsafe { to_owned_from_range(range) }
+}
+
+unsafe fn box_is_unique(data: *mut ()) -> bool {
+    let backing: &BoxBacking = unsafe { &*(data as *const BoxBacking) };
+    backing.arc.load(atomic::Ordering::Acquire) == 1
+}
+
+unsafe fn box_full_range(data: *mut ()) -> Range<usize> {
+    let backing: &BoxBacking = unsafe { &*(data as *const BoxBacking) };
+    let start = backing.boxed.as_ptr() as usize;
+    start..(start + backing.boxed.len())
+}
+
+// --- a `&'static [u8]` allocation: never shared via refcount, never freed ---
+
+pub(crate) static STATIC_BACKING: Backing = Backing {
+    clone: static_clone,
+    drop: static_drop,
+    to_owned: static_to_owned,
+    is_unique: static_is_unique,
+    full_range: static_full_range,
+};
+
+pub(crate) fn adopt_static(slice: &'static [u8]) -> LoafPart {
+    let start = slice.as_ptr() as usize;
+    let end = start + slice.len();
+    // `data` carries the origin slice's start address as an identity token
+    // (it's never dereferenced): two adoptions of distinct statics get
+    // distinct `data`, so `LoafPart::same_backing` can't conflate them, while
+    // every part split/cloned from one adoption keeps sharing it.
+    unsafe { LoafPart::from_raw_parts(start as *mut (), &STATIC_BACKING, start..end) }
+}
+
+unsafe fn static_clone(data: *mut (), range: Range<usize>) -> LoafPart {
+    unsafe { LoafPart::from_raw_parts(data, &STATIC_BACKING, range) }
+}
+
+unsafe fn static_drop(_data: *mut ()) {
+    // nothing to do: `&'static [u8]` is never owned by us.
+}
+
+unsafe fn static_to_owned(_data: *mut (), range: Range<usize>) -> Vec<u8> {
+    unsafe { to_owned_from_range(range) }
+}
+
+unsafe fn static_is_unique(_data: *mut ()) -> bool {
+    // a `&'static` slice is never exclusively ours to reclaim.
+    false
+}
+
+unsafe fn static_full_range(_data: *mut ()) -> Range<usize> {
+    unreachable!("static_is_unique always returns false, so this is never queried")
+}
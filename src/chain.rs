@@ -0,0 +1,82 @@
+use crate::LoafPart;
+use std::collections::VecDeque;
+use std::io::IoSlice;
+
+/// An ordered, logically-contiguous sequence of [`LoafPart`]s, possibly drawn
+/// from different allocations.
+///
+/// `try_join` only works for physically adjacent parts in the same
+/// allocation; `LoafChain` instead lets fragmented loaves (e.g. received
+/// network fragments) be read as a single byte stream without copying them
+/// into one buffer.
+#[derive(Debug, Default)]
+pub struct LoafChain {
+    parts: VecDeque<LoafPart>,
+    front_offset: usize,
+}
+
+impl LoafChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a loaf to the back of the chain. Empty loaves are dropped
+    /// immediately rather than kept around as no-op chunks.
+    pub fn push(&mut self, part: LoafPart) {
+        if !part.is_empty() {
+            self.parts.push_back(part);
+        }
+    }
+
+    /// The total number of unread bytes across every loaf in the chain.
+    pub fn remaining(&self) -> usize {
+        self.parts.iter().map(LoafPart::len).sum::<usize>() - self.front_offset
+    }
+
+    /// The unread tail of the front loaf. Empty once the chain is drained.
+    pub fn chunk(&self) -> &[u8] {
+        match self.parts.front() {
+            Some(front) => &front.as_slice()[self.front_offset..],
+            None => &[],
+        }
+    }
+
+    /// Advances the cursor by `cnt` bytes, dropping any loaf that becomes
+    /// fully consumed along the way.
+    ///
+    /// Panics if `cnt` exceeds `self.remaining()`.
+    pub fn advance(&mut self, mut cnt: usize) {
+        while cnt > 0 {
+            let front_len = match self.parts.front() {
+                Some(front) => front.len() - self.front_offset,
+                None => panic!("advance past end of chain"),
+            };
+            if cnt < front_len {
+                self.front_offset += cnt;
+                cnt = 0;
+            } else {
+                cnt -= front_len;
+                self.parts.pop_front();
+                self.front_offset = 0;
+            }
+        }
+    }
+
+    /// Builds an `IoSlice` per remaining loaf, in order, suitable for a
+    /// vectored write (e.g. `Write::write_vectored`).
+    pub fn chunks_vectored(&self) -> Vec<IoSlice<'_>> {
+        self.parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| {
+                if i == 0 {
+                    &part.as_slice()[self.front_offset..]
+                } else {
+                    part.as_slice()
+                }
+            })
+            .filter(|bytes| !bytes.is_empty())
+            .map(IoSlice::new)
+            .collect()
+    }
+}
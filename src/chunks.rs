@@ -0,0 +1,72 @@
+use crate::LoafPart;
+
+/// An owning iterator over fixed-size pieces of a loaf.
+///
+/// Returned by [`LoafPart::into_chunks`]. Each step splits the front
+/// `chunk_len` bytes off the remaining loaf via `try_split_at`, so every
+/// yielded chunk is an independently owned, refcounted handle; the final
+/// chunk may be shorter than `chunk_len`.
+pub struct LoafChunks {
+    remainder: Option<LoafPart>,
+    chunk_len: usize,
+}
+
+impl LoafChunks {
+    pub(crate) fn new(loaf: LoafPart, chunk_len: usize) -> Self {
+        assert!(chunk_len > 0, "chunk length must be greater than zero");
+        Self {
+            remainder: Some(loaf),
+            chunk_len,
+        }
+    }
+}
+
+impl Iterator for LoafChunks {
+    type Item = LoafPart;
+    fn next(&mut self) -> Option<LoafPart> {
+        let remainder = self.remainder.take()?;
+        if remainder.len() <= self.chunk_len {
+            Some(remainder)
+        } else {
+            let [chunk, rest] = remainder.with_try_split_at(self.chunk_len).unwrap();
+            self.remainder = Some(rest);
+            Some(chunk)
+        }
+    }
+}
+
+/// An owning iterator over the pieces of a loaf separated by a delimiter
+/// byte.
+///
+/// Returned by [`LoafPart::split_on`]. Mirrors slice/str split semantics: the
+/// delimiter itself is dropped, and a trailing delimiter yields a final
+/// empty piece.
+pub struct LoafSplit {
+    remainder: Option<LoafPart>,
+    delimiter: u8,
+}
+
+impl LoafSplit {
+    pub(crate) fn new(loaf: LoafPart, delimiter: u8) -> Self {
+        Self {
+            remainder: Some(loaf),
+            delimiter,
+        }
+    }
+}
+
+impl Iterator for LoafSplit {
+    type Item = LoafPart;
+    fn next(&mut self) -> Option<LoafPart> {
+        let remainder = self.remainder.take()?;
+        match remainder.as_slice().iter().position(|&b| b == self.delimiter) {
+            Some(idx) => {
+                let [piece, rest] = remainder.with_try_split_at(idx).unwrap();
+                let [_delimiter, rest] = rest.with_try_split_at(1).unwrap();
+                self.remainder = Some(rest);
+                Some(piece)
+            }
+            None => Some(remainder),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::LoafPart;
+
+// Generates a `get_*` method on `LoafReader` that reads a fixed-width
+// integer from the front of the cursor and advances past it.
+macro_rules! get_impl {
+    ($name:ident, $ty:ty, $from_bytes:ident) => {
+        pub fn $name(&mut self) -> $ty {
+            const SIZE: usize = core::mem::size_of::<$ty>();
+            let bytes: [u8; SIZE] = self.chunk()[..SIZE].try_into().unwrap();
+            self.advance(SIZE);
+            <$ty>::$from_bytes(bytes)
+        }
+    };
+}
+
+// Generates a `put_*` method on `LoafWriter` that writes a fixed-width
+// integer to the front of the cursor and advances past it.
+macro_rules! put_impl {
+    ($name:ident, $ty:ty, $to_bytes:ident) => {
+        pub fn $name(&mut self, value: $ty) {
+            let bytes = value.$to_bytes();
+            self.chunk_mut()[..bytes.len()].copy_from_slice(&bytes);
+            self.advance(bytes.len());
+        }
+    };
+}
+
+/// A read cursor over a [`LoafPart`], tracking a `position` into its bytes.
+///
+/// Modeled on the `bytes` crate's `Buf` trait: `chunk()` exposes the
+/// unread tail as a slice, and `advance` moves the cursor forward, letting
+/// parsers walk a loaf without copying it.
+#[derive(Debug)]
+pub struct LoafReader {
+    loaf: LoafPart,
+    position: usize,
+}
+
+impl LoafReader {
+    pub fn new(loaf: LoafPart) -> Self {
+        Self { loaf, position: 0 }
+    }
+
+    /// The number of unread bytes remaining in the loaf.
+    pub fn remaining(&self) -> usize {
+        self.loaf.len() - self.position
+    }
+
+    /// The unread tail of the loaf.
+    pub fn chunk(&self) -> &[u8] {
+        &self.loaf.as_slice()[self.position..]
+    }
+
+    /// Advances the cursor by `cnt` bytes.
+    ///
+    /// Panics if `cnt` would advance the cursor past the end of the loaf.
+    pub fn advance(&mut self, cnt: usize) {
+        let new_position = self.position.checked_add(cnt).expect("advance overflowed");
+        assert!(
+            new_position <= self.loaf.len(),
+            "advance past end of loaf"
+        );
+        self.position = new_position;
+    }
+
+    /// Consumes the cursor, returning the underlying loaf unchanged.
+    pub fn into_inner(self) -> LoafPart {
+        self.loaf
+    }
+
+    pub fn get_u8(&mut self) -> u8 {
+        let byte = self.chunk()[0];
+        self.advance(1);
+        byte
+    }
+
+    pub fn get_i8(&mut self) -> i8 {
+        self.get_u8() as i8
+    }
+
+    get_impl!(get_u16_le, u16, from_le_bytes);
+    get_impl!(get_u16_be, u16, from_be_bytes);
+    get_impl!(get_i16_le, i16, from_le_bytes);
+    get_impl!(get_i16_be, i16, from_be_bytes);
+    get_impl!(get_u32_le, u32, from_le_bytes);
+    get_impl!(get_u32_be, u32, from_be_bytes);
+    get_impl!(get_i32_le, i32, from_le_bytes);
+    get_impl!(get_i32_be, i32, from_be_bytes);
+    get_impl!(get_u64_le, u64, from_le_bytes);
+    get_impl!(get_u64_be, u64, from_be_bytes);
+    get_impl!(get_i64_le, i64, from_le_bytes);
+    get_impl!(get_i64_be, i64, from_be_bytes);
+}
+
+/// A write cursor over a [`LoafPart`], tracking a `position` into its bytes.
+///
+/// Modeled on the `bytes` crate's `BufMut` trait: `chunk_mut()` exposes the
+/// unwritten tail as a mutable slice, and `advance` moves the cursor
+/// forward, letting serializers fill a loaf in place without copying.
+#[derive(Debug)]
+pub struct LoafWriter {
+    loaf: LoafPart,
+    position: usize,
+}
+
+impl LoafWriter {
+    pub fn new(loaf: LoafPart) -> Self {
+        Self { loaf, position: 0 }
+    }
+
+    /// The number of unwritten bytes remaining in the loaf.
+    pub fn remaining_mut(&self) -> usize {
+        self.loaf.len() - self.position
+    }
+
+    /// The unwritten tail of the loaf.
+    pub fn chunk_mut(&mut self) -> &mut [u8] {
+        &mut self.loaf.as_slice_mut()[self.position..]
+    }
+
+    /// Advances the cursor by `cnt` bytes.
+    ///
+    /// Panics if `cnt` would advance the cursor past the end of the loaf.
+    pub fn advance(&mut self, cnt: usize) {
+        let new_position = self.position.checked_add(cnt).expect("advance overflowed");
+        assert!(
+            new_position <= self.loaf.len(),
+            "advance past end of loaf"
+        );
+        self.position = new_position;
+    }
+
+    /// Consumes the cursor, returning the underlying loaf unchanged.
+    pub fn into_inner(self) -> LoafPart {
+        self.loaf
+    }
+
+    pub fn put_u8(&mut self, value: u8) {
+        self.chunk_mut()[0] = value;
+        self.advance(1);
+    }
+
+    pub fn put_i8(&mut self, value: i8) {
+        self.put_u8(value as u8)
+    }
+
+    put_impl!(put_u16_le, u16, to_le_bytes);
+    put_impl!(put_u16_be, u16, to_be_bytes);
+    put_impl!(put_i16_le, i16, to_le_bytes);
+    put_impl!(put_i16_be, i16, to_be_bytes);
+    put_impl!(put_u32_le, u32, to_le_bytes);
+    put_impl!(put_u32_be, u32, to_be_bytes);
+    put_impl!(put_i32_le, i32, to_le_bytes);
+    put_impl!(put_i32_be, i32, to_be_bytes);
+    put_impl!(put_u64_le, u64, to_le_bytes);
+    put_impl!(put_u64_be, u64, to_be_bytes);
+    put_impl!(put_i64_le, i64, to_le_bytes);
+    put_impl!(put_i64_be, i64, to_be_bytes);
+}
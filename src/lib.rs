@@ -1,6 +1,14 @@
 use core::{ops::Range, sync::atomic};
 use std::alloc;
 
+mod backing;
+mod chain;
+mod chunks;
+mod cursor;
+pub use chain::LoafChain;
+pub use chunks::{LoafChunks, LoafSplit};
+pub use cursor::{LoafReader, LoafWriter};
+
 #[cfg(test)]
 mod tests;
 
@@ -9,20 +17,38 @@ mod tests;
 /// and owned bytes can be accessed for reading and writing.
 /// The underlying buffer is owned by all Byteloaves owning it's slices,
 /// and is freed by the last of its Byteloaves to drop.
-#[derive(Debug)]
 pub struct LoafPart {
     // invariants:
-    // 1. `header_ptr` points to an allocation of (LoafHeader,X)
-    //	  where X is some sequence of bytes such that the entire allocation has size header.alloc_size
-    // 2. No other LoafHeader exists where (ptr_range.start..ptr_range.end) overlaps with mine.
-    header_ptr: usize,
+    // 1. `data` is whatever pointer `backing`'s functions expect to receive;
+    //    its meaning is opaque outside of `backing.rs`.
+    // 2. (ptr_range.start..ptr_range.end) is a currently-allocated, live range of
+    //    bytes, and no two `LoafPart`s sharing a `backing`/`data` pair hold
+    //    overlapping `ptr_range`s.
+    data: *mut (),
+    backing: &'static backing::Backing,
     ptr_range: Range<usize>,
 }
-const USIZE_BYTES: usize = std::mem::size_of::<usize>();
 
-struct LoafHeader {
+// SAFETY: `data` is never dereferenced as a plain pointer to shared mutable
+// state; every `backing` function either reads it through atomics (refcounts)
+// or treats it as an opaque identity token (the `&'static` backing). The
+// bytes it (indirectly) points at are only ever accessed through `&self`
+// or `&mut self` on this `LoafPart`, so Rust's usual borrow rules already
+// prevent unsynchronized cross-thread mutation - the same guarantee the
+// crate's original `header_ptr: usize` field carried implicitly. This
+// mirrors `bytes::Bytes`, which makes the same argument for its own
+// type-erased `data: AtomicPtr<()>`.
+unsafe impl Send for LoafPart {}
+unsafe impl Sync for LoafPart {}
+
+pub(crate) const USIZE_BYTES: usize = std::mem::size_of::<usize>();
+
+pub(crate) struct LoafHeader {
     arc: atomic::AtomicUsize,
     alloc_size: usize,
+    // the logical length the loaf was created with; `alloc_size` is this plus
+    // the header's size, rounded up to a multiple of `USIZE_BYTES`.
+    data_len: usize,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -44,7 +70,8 @@ const fn usize_bytes_round_up(x: usize) -> usize {
     usize_bytes_round_down(x + USIZE_BYTES - 1)
 }
 impl LoafPart {
-    pub const MAX_LOAF_LEN: usize = usize_bytes_round_down(isize::MAX as usize - (2 * USIZE_BYTES));
+    pub const MAX_LOAF_LEN: usize =
+        usize_bytes_round_down(isize::MAX as usize - core::mem::size_of::<LoafHeader>());
 
     pub fn new_from_slice(slice: &[u8]) -> Self {
         let mut me = Self::new(slice.len());
@@ -57,7 +84,7 @@ impl LoafPart {
         if loaf_len > Self::MAX_LOAF_LEN {
             panic!("Can't support loaf of that size!")
         }
-        let alloc_size = usize_bytes_round_up(loaf_len + 2 * USIZE_BYTES);
+        let alloc_size = usize_bytes_round_up(loaf_len + core::mem::size_of::<LoafHeader>());
         let header_ptr = unsafe {
             // safe! alloc_size is multiple of USIZE_BYTES which is a power of two.
             let layout = alloc::Layout::from_size_align_unchecked(alloc_size, USIZE_BYTES);
@@ -67,14 +94,39 @@ impl LoafPart {
         unsafe {
             // Header structure is in allocated space, well-aligned, and uniquely accessed
             let arc = 1.into();
-            header_ptr.write(LoafHeader { arc, alloc_size });
+            header_ptr.write(LoafHeader {
+                arc,
+                alloc_size,
+                data_len: loaf_len,
+            });
         }
         let ptr_range_start = header_ptr as usize + core::mem::size_of::<LoafHeader>();
         LoafPart {
-            header_ptr: header_ptr as usize,
+            data: header_ptr as *mut (),
+            backing: &backing::HEADER_BACKING,
             ptr_range: ptr_range_start..(ptr_range_start + loaf_len),
         }
     }
+    /// Constructs a `LoafPart` directly from its raw parts.
+    ///
+    /// Only called from within `backing.rs`'s vtable functions, which are
+    /// responsible for upholding `data`/`backing`/`ptr_range`'s invariants.
+    pub(crate) unsafe fn from_raw_parts(
+        data: *mut (),
+        backing: &'static backing::Backing,
+        ptr_range: Range<usize>,
+    ) -> Self {
+        LoafPart {
+            data,
+            backing,
+            ptr_range,
+        }
+    }
+    /// Whether `self` and `other` share the same backing allocation, and so
+    /// are eligible to be joined or resplit against one another.
+    fn same_backing(&self, other: &Self) -> bool {
+        self.data == other.data && core::ptr::eq(self.backing, other.backing)
+    }
     pub fn try_set_relative_range(
         &mut self,
         mut new_relative_range: Range<usize>,
@@ -98,7 +150,7 @@ impl LoafPart {
         self.as_slice().len()
     }
     pub fn try_join(&mut self, other: &mut Self) -> Result<(), JoinError> {
-        if self.header_ptr != other.header_ptr {
+        if !self.same_backing(other) {
             Err(JoinError::DistinctLoaves)
         } else if self.ptr_range.end != other.ptr_range.start {
             Err(JoinError::PartsAreNotAdjacent)
@@ -113,7 +165,7 @@ impl LoafPart {
         other: &mut Self,
         new_self_len: usize,
     ) -> Result<(), ResplitError> {
-        if self.header_ptr != other.header_ptr {
+        if !self.same_backing(other) {
             Err(ResplitError::DistinctLoaves)
         } else if self.ptr_range.end != other.ptr_range.start {
             Err(ResplitError::PartsAreNotAdjacent)
@@ -136,21 +188,12 @@ impl LoafPart {
         let middle = self.ptr_range.start + new_self_len;
         self.ptr_range.end = middle;
 
-        let header_ptr = self.header_ptr as *mut LoafHeader;
-        let header_ref: &LoafHeader = unsafe {
-            // safe! pointer points to initialized LoafHeader value,
-            // which is currently not mut accessed elsewhere
-            &*header_ptr
+        let tail = unsafe {
+            // safe! `middle..end` is a subrange of the range `self` held before
+            // the split, so it's still a live, valid range of the same backing.
+            (self.backing.clone)(self.data, middle..end)
         };
-        let was = header_ref.arc.fetch_add(1, atomic::Ordering::SeqCst);
-        if was > usize::MAX {
-            std::process::abort();
-        }
-
-        Ok(Self {
-            header_ptr: self.header_ptr,
-            ptr_range: middle..end,
-        })
+        Ok(tail)
     }
     pub fn as_slice(&self) -> &[u8] {
         unsafe {
@@ -162,6 +205,47 @@ impl LoafPart {
             std::slice::from_raw_parts_mut(self.ptr_range.start as *mut u8, self.ptr_range.len())
         }
     }
+    /// Reconstructs an owning, refcounted `LoafPart` from a subslice of `self.as_slice()`.
+    ///
+    /// Panics if a non-empty `sub` is not contained within this loaf's bytes.
+    /// An empty `sub` always yields a new, empty loaf, wherever it points.
+    pub fn slice_ref(&self, sub: &[u8]) -> LoafPart {
+        // an empty slice need not point inside this loaf at all (it may be
+        // dangling or point at a foreign allocation), so it's handled before
+        // the pointer-range check.
+        if sub.is_empty() {
+            return LoafPart::new(0);
+        }
+        let sub_start = sub.as_ptr() as usize;
+        let sub_end = sub_start + sub.len();
+        assert!(
+            self.ptr_range.start <= sub_start && sub_end <= self.ptr_range.end,
+            "subslice is not a part of this loaf"
+        );
+
+        unsafe {
+            // safe! `sub_start..sub_end` was just checked to be a subrange of
+            // `self.ptr_range`, so it's a live, valid range of the same backing.
+            (self.backing.clone)(self.data, sub_start..sub_end)
+        }
+    }
+    /// Copies this loaf's bytes into a new, independent `Vec<u8>`, detached
+    /// from whatever allocation backs this loaf.
+    pub fn to_vec(&self) -> Vec<u8> {
+        unsafe {
+            // safe! `self.data`/`self.ptr_range` are this loaf's own live, valid range.
+            (self.backing.to_owned)(self.data, self.ptr_range.clone())
+        }
+    }
+    /// Whether this loaf is the sole remaining reference to its backing
+    /// allocation, i.e. no sibling loaf shares the same underlying bytes.
+    /// Always `false` for a loaf adopted from a `&'static [u8]`.
+    pub fn is_unique(&self) -> bool {
+        unsafe {
+            // safe! `self.data` is the pointer `self.backing` was constructed with.
+            (self.backing.is_unique)(self.data)
+        }
+    }
     pub fn get_ptr_range(&self) -> &Range<usize> {
         &self.ptr_range
     }
@@ -193,23 +277,42 @@ impl LoafPart {
             Err(()) => Err(self),
         }
     }
+    /// Reclaims the full backing allocation for reuse, succeeding only when
+    /// this loaf is both unique and covers the allocation's entire data
+    /// region (no other loaf could have split off a neighbouring piece).
+    pub fn try_into_unique(self) -> Result<LoafPart, LoafPart> {
+        if !self.is_unique() {
+            return Err(self);
+        }
+        let full_range = unsafe {
+            // safe! `self.is_unique()` just confirmed exclusive ownership.
+            (self.backing.full_range)(self.data)
+        };
+        if self.ptr_range == full_range {
+            Ok(self)
+        } else {
+            Err(self)
+        }
+    }
+    /// Splits this loaf into successive `chunk_len`-byte pieces (the last may
+    /// be shorter), each an independently owned, refcounted handle.
+    ///
+    /// Panics if `chunk_len` is zero.
+    pub fn into_chunks(self, chunk_len: usize) -> LoafChunks {
+        LoafChunks::new(self, chunk_len)
+    }
+    /// Splits this loaf into pieces separated by `delimiter`, dropping the
+    /// delimiter bytes themselves.
+    pub fn split_on(self, delimiter: u8) -> LoafSplit {
+        LoafSplit::new(self, delimiter)
+    }
 }
 
 impl Drop for LoafPart {
     fn drop(&mut self) {
-        let header_ptr = self.header_ptr as *mut LoafHeader;
-        let header_ref: &LoafHeader = unsafe { &*header_ptr };
-        let was = header_ref.arc.fetch_sub(1, atomic::Ordering::SeqCst);
-        if was == 1 {
-            // I am the final owner! drop!
-            let layout = unsafe {
-                // safe!
-                alloc::Layout::from_size_align_unchecked(header_ref.alloc_size, USIZE_BYTES)
-            };
-            unsafe {
-                // safe!
-                alloc::dealloc(self.header_ptr as *mut u8, layout)
-            }
+        unsafe {
+            // safe! `self.data` is the pointer `self.backing` was constructed with.
+            (self.backing.drop)(self.data)
         }
     }
 }
@@ -223,3 +326,40 @@ impl AsMut<[u8]> for LoafPart {
         self.as_slice_mut()
     }
 }
+impl core::fmt::Debug for LoafPart {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LoafPart")
+            .field("data", &self.data)
+            .field("ptr_range", &self.ptr_range)
+            .finish()
+    }
+}
+
+/// Adopts an existing `Vec<u8>`'s buffer.
+///
+/// The buffer is shrunk to fit (via [`Vec::into_boxed_slice`]) and then
+/// shares a refcount the same way a crate-allocated loaf does, so it can be
+/// split, joined and dropped like any other `LoafPart`.
+///
+/// A vec with no spare capacity is adopted without copying; shrinking a vec
+/// that has spare capacity may move its bytes into a new allocation.
+impl From<Vec<u8>> for LoafPart {
+    fn from(vec: Vec<u8>) -> Self {
+        backing::adopt_boxed(vec.into_boxed_slice())
+    }
+}
+
+/// Adopts an existing `Box<[u8]>`'s buffer without copying it.
+impl From<Box<[u8]>> for LoafPart {
+    fn from(boxed: Box<[u8]>) -> Self {
+        backing::adopt_boxed(boxed)
+    }
+}
+
+/// Adopts a `&'static [u8]` without copying it. The slice is never freed,
+/// so no refcounting is needed to keep it alive.
+impl From<&'static [u8]> for LoafPart {
+    fn from(slice: &'static [u8]) -> Self {
+        backing::adopt_static(slice)
+    }
+}
@@ -53,3 +53,208 @@ fn new_range() {
         .unwrap();
     assert_eq!(llo_w.as_slice(), b"LLO_W");
 }
+
+#[test]
+fn slice_ref_roundtrip() {
+    let hello_world = LoafPart::new_from_slice(HELLO_WORLD);
+    let sub = &hello_world.as_slice()[2..7];
+    let llo_w = hello_world.slice_ref(sub);
+    assert_eq!(llo_w.as_slice(), b"LLO_W");
+}
+
+#[test]
+fn slice_ref_empty() {
+    let hello_world = LoafPart::new_from_slice(HELLO_WORLD);
+    let empty = hello_world.slice_ref(&[]);
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn slice_ref_foreign_slice_panics() {
+    let hello_world = LoafPart::new_from_slice(HELLO_WORLD);
+    let other = [0u8; 4];
+    hello_world.slice_ref(&other);
+}
+
+#[test]
+fn from_vec_zero_copy() {
+    let vec = HELLO_WORLD.to_vec();
+    let ptr_before = vec.as_ptr();
+    let loaf = LoafPart::from(vec);
+    assert_eq!(loaf.as_slice(), HELLO_WORLD);
+    assert_eq!(loaf.as_slice().as_ptr(), ptr_before);
+}
+
+#[test]
+fn from_boxed_slice_splits_and_joins() {
+    let boxed: Box<[u8]> = HELLO_WORLD.to_vec().into_boxed_slice();
+    let loaf = LoafPart::from(boxed);
+    let [hello, world] = loaf.with_try_split_at(5).unwrap();
+    assert_eq!(hello.as_slice(), b"HELLO");
+    assert_eq!(world.as_slice(), b"_WORLD");
+    hello.with_try_join(world).unwrap();
+}
+
+#[test]
+fn from_static_slice() {
+    let loaf = LoafPart::from(HELLO_WORLD);
+    assert_eq!(loaf.as_slice(), HELLO_WORLD);
+}
+
+#[test]
+fn loaf_part_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<LoafPart>();
+}
+
+#[test]
+fn distinct_static_adoptions_do_not_join_even_when_adjacent() {
+    const ADJACENT: &[u8; 8] = b"ABCDEFGH";
+    // two independent adoptions of adjacent subslices of the same `'static`
+    // array must still be treated as distinct backings, not silently merged.
+    let mut first = LoafPart::from(&ADJACENT[0..4]);
+    let mut second = LoafPart::from(&ADJACENT[4..8]);
+    assert_eq!(
+        first.try_join(&mut second),
+        Err(JoinError::DistinctLoaves)
+    );
+}
+
+#[test]
+fn join_across_distinct_backings_fails() {
+    let mut from_static = LoafPart::from(HELLO_WORLD);
+    let mut owned = LoafPart::new_from_slice(HELLO_WORLD);
+    assert_eq!(
+        from_static.try_join(&mut owned),
+        Err(JoinError::DistinctLoaves)
+    );
+}
+
+#[test]
+fn chain_reads_across_fragments() {
+    let [hello, world] = LoafPart::new_from_slice(HELLO_WORLD)
+        .with_try_split_at(5)
+        .unwrap();
+    let mut chain = LoafChain::new();
+    chain.push(hello);
+    chain.push(world);
+    assert_eq!(chain.remaining(), HELLO_WORLD.len());
+    assert_eq!(chain.chunk(), b"HELLO");
+
+    chain.advance(7);
+    assert_eq!(chain.chunk(), b"ORLD");
+    assert_eq!(chain.remaining(), 4);
+
+    chain.advance(4);
+    assert_eq!(chain.remaining(), 0);
+    assert_eq!(chain.chunk(), b"");
+}
+
+#[test]
+fn chain_vectored_chunks() {
+    let [hello, world] = LoafPart::new_from_slice(HELLO_WORLD)
+        .with_try_split_at(5)
+        .unwrap();
+    let mut chain = LoafChain::new();
+    chain.push(hello);
+    chain.push(world);
+    let slices = chain.chunks_vectored();
+    assert_eq!(slices.len(), 2);
+    assert_eq!(&*slices[0], b"HELLO");
+    assert_eq!(&*slices[1], b"_WORLD");
+}
+
+#[test]
+fn to_vec_copies_bytes() {
+    let loaf = LoafPart::new_from_slice(HELLO_WORLD);
+    let copy = loaf.to_vec();
+    assert_eq!(copy, HELLO_WORLD);
+    assert_ne!(copy.as_ptr(), loaf.as_slice().as_ptr());
+}
+
+#[test]
+fn is_unique_after_split() {
+    let whole = LoafPart::new_from_slice(HELLO_WORLD);
+    assert!(whole.is_unique());
+
+    let [hello, world] = whole.with_try_split_at(5).unwrap();
+    assert!(!hello.is_unique());
+    assert!(!world.is_unique());
+}
+
+#[test]
+fn try_into_unique_requires_full_coverage() {
+    let whole = LoafPart::new_from_slice(HELLO_WORLD);
+    let [hello, world] = whole.with_try_split_at(5).unwrap();
+
+    // neither half is unique (the other half shares the allocation)
+    let hello = hello.try_into_unique().unwrap_err();
+    // rejoining restores both exclusivity and full coverage
+    let whole = hello.with_try_join(world).unwrap();
+    assert!(whole.try_into_unique().is_ok());
+
+    // a unique loaf that covers only part of its allocation is still rejected
+    let [hello, world] = LoafPart::new_from_slice(HELLO_WORLD)
+        .with_try_split_at(5)
+        .unwrap();
+    drop(world);
+    assert!(hello.is_unique());
+    assert!(hello.try_into_unique().is_err());
+}
+
+#[test]
+fn static_backed_loaf_is_never_unique() {
+    let loaf = LoafPart::from(HELLO_WORLD);
+    assert!(!loaf.is_unique());
+    assert!(loaf.try_into_unique().is_err());
+}
+
+#[test]
+fn into_chunks_yields_fixed_size_pieces() {
+    let loaf = LoafPart::new_from_slice(HELLO_WORLD);
+    let chunks: Vec<LoafPart> = loaf.into_chunks(4).collect();
+    let as_slices: Vec<&[u8]> = chunks.iter().map(LoafPart::as_slice).collect();
+    assert_eq!(as_slices, [b"HELL".as_slice(), b"O_WO".as_slice(), b"RLD".as_slice()]);
+}
+
+#[test]
+fn split_on_delimiter() {
+    let loaf = LoafPart::new_from_slice(b"a,bb,,c");
+    let pieces: Vec<LoafPart> = loaf.split_on(b',').collect();
+    let as_slices: Vec<&[u8]> = pieces.iter().map(LoafPart::as_slice).collect();
+    assert_eq!(
+        as_slices,
+        [
+            b"a".as_slice(),
+            b"bb".as_slice(),
+            b"".as_slice(),
+            b"c".as_slice()
+        ]
+    );
+}
+
+#[test]
+fn reader_cursor() {
+    let loaf = LoafPart::new_from_slice(HELLO_WORLD);
+    let mut reader = LoafReader::new(loaf);
+    assert_eq!(reader.remaining(), HELLO_WORLD.len());
+    assert_eq!(reader.chunk(), HELLO_WORLD);
+    reader.advance(6);
+    assert_eq!(reader.chunk(), b"WORLD");
+    assert_eq!(reader.remaining(), 5);
+}
+
+#[test]
+fn writer_cursor_roundtrip() {
+    let loaf = LoafPart::new(8);
+    let mut writer = LoafWriter::new(loaf);
+    writer.put_u32_le(0xDEAD_BEEF);
+    writer.put_u32_be(0xCAFE_F00D);
+    let loaf = writer.into_inner();
+
+    let mut reader = LoafReader::new(loaf);
+    assert_eq!(reader.get_u32_le(), 0xDEAD_BEEF);
+    assert_eq!(reader.get_u32_be(), 0xCAFE_F00D);
+    assert_eq!(reader.remaining(), 0);
+}